@@ -10,7 +10,7 @@ const AACHEN: (f64, f64) = (6.186389, 50.823194);
 const MEIERSBERG: (f64, f64) = (6.953333, 51.301389);
 
 fn flat_distance(p1: (f64, f64), p2: (f64, f64)) -> f64 {
-    let proj = FlatProjection::new((p1.1 + p2.1) / 2.);
+    let proj = FlatProjection::new((p1.0 + p2.0) / 2., (p1.1 + p2.1) / 2.);
 
     let flat1 = proj.project(p1.0, p1.1);
     let flat2 = proj.project(p2.0, p2.1);
@@ -102,5 +102,31 @@ fn criterion_benchmark(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, criterion_benchmark);
+fn criterion_benchmark_project_many(c: &mut Criterion) {
+    let proj = FlatProjection::new((AACHEN.0 + MEIERSBERG.0) / 2., (AACHEN.1 + MEIERSBERG.1) / 2.);
+
+    let coords: Vec<(f64, f64)> = (0..1000)
+        .map(|i| {
+            let t = f64::from(i) / 1000.;
+            (AACHEN.0 + (MEIERSBERG.0 - AACHEN.0) * t, AACHEN.1 + (MEIERSBERG.1 - AACHEN.1) * t)
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("project_many");
+
+    group.bench_with_input("loop", &coords, |b, coords| {
+        b.iter(|| coords.iter().map(|&(lon, lat)| proj.project(lon, lat)).collect::<Vec<_>>())
+    });
+    group.bench_with_input("batch", &coords, |b, coords| {
+        b.iter(|| {
+            let mut out = Vec::with_capacity(coords.len());
+            proj.project_many(coords, &mut out);
+            out
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark, criterion_benchmark_project_many);
 criterion_main!(benches);
@@ -0,0 +1,85 @@
+//! Conversions between this crate's types and [`geo_types`] geometries,
+//! enabled by the `geo-types` feature.
+//!
+//! This lets [`FlatProjection`] be used directly with the [georust]
+//! ecosystem, e.g. to compute fast approximate [`line_distance`]/[`area`]
+//! over a `geo_types::LineString` without manually unpacking coordinates.
+//!
+//! [`FlatProjection`]: ../struct.FlatProjection.html
+//! [`line_distance`]: ../fn.line_distance.html
+//! [`area`]: ../fn.area.html
+//! [georust]: https://georust.org/
+//! [`geo_types`]: https://docs.rs/geo-types
+
+extern crate geo_types;
+
+use num_traits::Float;
+use self::geo_types::{Coord, Point, LineString};
+use {FlatProjection, FlatPoint};
+
+impl<T: Float + ::std::fmt::Debug> FlatProjection<T> {
+    /// Converts a `geo_types::Point` to a [`FlatPoint`] instance that
+    /// can be used for fast geodesic approximations.
+    ///
+    /// [`FlatPoint`]: ../struct.FlatPoint.html
+    pub fn project_point(&self, point: Point<T>) -> FlatPoint<T> {
+        self.project(point.x(), point.y())
+    }
+
+    /// Converts a [`FlatPoint`] back to a `geo_types::Point`.
+    ///
+    /// [`FlatPoint`]: ../struct.FlatPoint.html
+    pub fn unproject_point(&self, p: &FlatPoint<T>) -> Point<T> {
+        let (lon, lat) = self.unproject(p);
+
+        Point::new(lon, lat)
+    }
+
+    /// Converts a `geo_types::LineString` to a `Vec` of [`FlatPoint`]s
+    /// that can be used for fast geodesic approximations, e.g. with
+    /// [`line_distance`] or [`area`].
+    ///
+    /// [`FlatPoint`]: ../struct.FlatPoint.html
+    /// [`line_distance`]: ../fn.line_distance.html
+    /// [`area`]: ../fn.area.html
+    pub fn project_line_string(&self, line_string: &LineString<T>) -> Vec<FlatPoint<T>> {
+        line_string.coords().map(|c| self.project(c.x, c.y)).collect()
+    }
+}
+
+impl<T: Float + ::std::fmt::Debug> From<FlatPoint<T>> for Coord<T> {
+    fn from(p: FlatPoint<T>) -> Coord<T> {
+        Coord { x: p.x, y: p.y }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::geo_types::{Point, LineString};
+    use FlatProjection;
+
+    #[test]
+    fn project_point_roundtrips_through_unproject_point() {
+        let proj = FlatProjection::new(6., 51.);
+        let point = Point::new(6.186389, 50.823194);
+
+        let flat_point = proj.project_point(point);
+        let result = proj.unproject_point(&flat_point);
+
+        assert_eq!(result, point);
+    }
+
+    #[test]
+    fn project_line_string_projects_each_coordinate() {
+        let proj = FlatProjection::new(6.5, 51.05);
+        let line_string = LineString::from(vec![
+            (6.186389, 50.823194),
+            (6.953333, 51.301389),
+        ]);
+
+        let points = proj.project_line_string(&line_string);
+
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0], proj.project(6.186389, 50.823194));
+    }
+}
@@ -49,6 +49,11 @@ extern crate num_traits;
 
 use num_traits::Float;
 
+mod vincenty;
+
+#[cfg(feature = "geo-types")]
+mod geo_types;
+
 /// Projection from [WGS84] to a cartesian coordinate system for fast
 /// geodesic approximations.
 ///
@@ -85,6 +90,8 @@ pub struct FlatProjection<T: Float> {
 
     lat: T,
     lon: T,
+
+    elevation: T,
 }
 
 impl<T: Float> FlatProjection<T> {
@@ -97,6 +104,25 @@ impl<T: Float> FlatProjection<T> {
     /// let proj = FlatProjection::new(7., 51.);
     /// ```
     pub fn new(longitude: T, latitude: T) -> FlatProjection<T> {
+        Self::new_with_elevation(longitude, latitude, T::zero())
+    }
+
+    /// Creates a new `FlatProjection` instance that will work best around
+    /// the given longitude, latitude and elevation (in kilometers).
+    ///
+    /// The elevation is used as the reference altitude for [`project_3d`],
+    /// similar to the local origin of an [ENU] (East-North-Up) tangent
+    /// plane.
+    ///
+    /// [`project_3d`]: #method.project_3d
+    /// [ENU]: https://en.wikipedia.org/wiki/Local_tangent_plane_coordinates
+    ///
+    /// ```
+    /// # use flat_projection::FlatProjection;
+    /// #
+    /// let proj = FlatProjection::new_with_elevation(7., 51., 0.3);
+    /// ```
+    pub fn new_with_elevation(longitude: T, latitude: T, elevation: T) -> FlatProjection<T> {
         // see https://github.com/mapbox/cheap-ruler/
 
         let one = T::one();
@@ -116,7 +142,7 @@ impl<T: Float> FlatProjection<T> {
         let kx = (re * w * cos_lat).to_radians();        // based on normal radius of curvature
         let ky = (re * w * w2 * (one - e2)).to_radians();  // based on meridional radius of curvature
 
-        FlatProjection { kx, ky, lat: latitude, lon: longitude }
+        FlatProjection { kx, ky, lat: latitude, lon: longitude, elevation }
     }
 
     /// Converts a longitude and latitude (in degrees) to a [`FlatPoint`]
@@ -162,6 +188,256 @@ impl<T: Float> FlatProjection<T> {
     pub fn unproject(&self, p: &FlatPoint<T>) -> (T, T) {
         (p.x / self.kx + self.lon, p.y / self.ky + self.lat)
     }
+
+    /// Converts a longitude, latitude (in degrees) and altitude (in
+    /// kilometers) to a [`FlatPoint3`] instance, the way [`project`]
+    /// converts a longitude and latitude to a [`FlatPoint`].
+    ///
+    /// The `z` component is the altitude relative to this projection's
+    /// reference elevation (see [`new_with_elevation`]), not the
+    /// altitude itself.
+    ///
+    /// Just like the flat-plane approximation of [`x`]/[`y`] this is only
+    /// accurate close to the reference point: the horizontal error of
+    /// this local tangent-plane projection grows with distance, so
+    /// 3D distances inherit the same ~500 km validity window as
+    /// [`distance`].
+    ///
+    /// [`FlatPoint3`]: struct.FlatPoint3.html
+    /// [`project`]: #method.project
+    /// [`FlatPoint`]: struct.FlatPoint.html
+    /// [`new_with_elevation`]: #method.new_with_elevation
+    /// [`x`]: struct.FlatPoint.html#structfield.x
+    /// [`y`]: struct.FlatPoint.html#structfield.y
+    /// [`distance`]: struct.FlatPoint.html#method.distance
+    ///
+    /// ```
+    /// # use flat_projection::FlatProjection;
+    /// #
+    /// let (lon, lat, alt) = (6.186389, 50.823194, 0.189);
+    ///
+    /// let proj = FlatProjection::new_with_elevation(6., 51., 0.1);
+    ///
+    /// let flat_point = proj.project_3d(lon, lat, alt);
+    /// ```
+    pub fn project_3d(&self, longitude: T, latitude: T, altitude: T) -> FlatPoint3<T> {
+        let FlatPoint { x, y } = self.project(longitude, latitude);
+
+        FlatPoint3 { x, y, z: altitude - self.elevation }
+    }
+
+    /// Converts a [`FlatPoint3`] back to a (lon, lat, alt) tuple.
+    ///
+    /// [`FlatPoint3`]: struct.FlatPoint3.html
+    ///
+    /// ```
+    /// # use flat_projection::FlatProjection;
+    /// #
+    /// let (lon, lat, alt) = (6.186389, 50.823194, 0.189);
+    ///
+    /// let proj = FlatProjection::new_with_elevation(6., 51., 0.1);
+    ///
+    /// let flat_point = proj.project_3d(lon, lat, alt);
+    ///
+    /// let result = proj.unproject_3d(&flat_point);
+    ///
+    /// assert_eq!(result.0, lon);
+    /// assert_eq!(result.1, lat);
+    /// assert_eq!(result.2, alt);
+    /// ```
+    pub fn unproject_3d(&self, p: &FlatPoint3<T>) -> (T, T, T) {
+        let (lon, lat) = self.unproject(&FlatPoint { x: p.x, y: p.y });
+
+        (lon, lat, p.z + self.elevation)
+    }
+
+    /// Returns a `(west, south, east, north)` bounding box in degrees
+    /// around the given longitude and latitude, padded by `radius`
+    /// kilometers in every direction.
+    ///
+    /// This is a coarse, cheap pre-filter for range queries (e.g. "find
+    /// everything within 5 km"): check [`inside_bbox`] first and only
+    /// fall back to an exact [`distance`] calculation for the points
+    /// that pass.
+    ///
+    /// [`inside_bbox`]: fn.inside_bbox.html
+    /// [`distance`]: struct.FlatPoint.html#method.distance
+    ///
+    /// ```
+    /// # use flat_projection::FlatProjection;
+    /// #
+    /// let proj = FlatProjection::new(6.5, 51.05);
+    ///
+    /// let bbox = proj.buffer_point(6.5, 51.05, 5.);
+    /// ```
+    pub fn buffer_point(&self, longitude: T, latitude: T, radius: T) -> (T, T, T, T) {
+        let v = radius / self.ky;
+        let h = radius / self.kx;
+
+        (longitude - h, latitude - v, longitude + h, latitude + v)
+    }
+
+    /// Pads an existing `(west, south, east, north)` bounding box in
+    /// degrees by `radius` kilometers in every direction.
+    ///
+    /// ```
+    /// # use flat_projection::FlatProjection;
+    /// #
+    /// let proj = FlatProjection::new(6.5, 51.05);
+    ///
+    /// let bbox = proj.buffer_bbox((6.1, 50.8, 7.0, 51.3), 5.);
+    /// ```
+    pub fn buffer_bbox(&self, bbox: (T, T, T, T), radius: T) -> (T, T, T, T) {
+        let v = radius / self.ky;
+        let h = radius / self.kx;
+
+        (bbox.0 - h, bbox.1 - v, bbox.2 + h, bbox.3 + v)
+    }
+
+    /// Calculates the destination longitude and latitude (in degrees)
+    /// reached by travelling `dist` kilometers along the given initial
+    /// `bearing` (in degrees) from the given starting point, using the
+    /// Vincenty direct formula on the WGS84 ellipsoid.
+    ///
+    /// This is much slower than projecting and calling
+    /// [`FlatPoint::destination`], but correct at any distance, not just
+    /// within this projection's ~500 km validity window. Prefer the fast
+    /// path unless you need an exact reference value.
+    ///
+    /// [`FlatPoint::destination`]: struct.FlatPoint.html#method.destination
+    ///
+    /// ```
+    /// # use flat_projection::FlatProjection;
+    /// #
+    /// let proj = FlatProjection::new(31., 50.);
+    ///
+    /// let (lon, lat) = proj.destination_exact(30.5, 50.5, 1., 45.);
+    /// ```
+    pub fn destination_exact(&self, longitude: T, latitude: T, dist: T, bearing: T) -> (T, T) {
+        vincenty::destination(longitude, latitude, dist, bearing)
+    }
+
+    /// Projects a whole slice of (lon, lat) coordinates at once, appending
+    /// each resulting [`FlatPoint`] to `out`.
+    ///
+    /// This avoids the per-point method-call overhead of calling
+    /// [`project`] in a loop, which matters when projecting large batches
+    /// of points (e.g. scoring thousands of track fixes).
+    ///
+    /// [`FlatPoint`]: struct.FlatPoint.html
+    /// [`project`]: #method.project
+    ///
+    /// ```
+    /// # use flat_projection::FlatProjection;
+    /// #
+    /// let proj = FlatProjection::new(6.5, 51.05);
+    ///
+    /// let coords = [(6.186389, 50.823194), (6.953333, 51.301389)];
+    ///
+    /// let mut points = Vec::with_capacity(coords.len());
+    /// proj.project_many(&coords, &mut points);
+    /// ```
+    pub fn project_many(&self, coords: &[(T, T)], out: &mut Vec<FlatPoint<T>>) {
+        let (kx, ky, lon, lat) = (self.kx, self.ky, self.lon, self.lat);
+
+        out.extend(coords.iter().map(|&(longitude, latitude)| {
+            FlatPoint { x: (longitude - lon) * kx, y: (latitude - lat) * ky }
+        }));
+    }
+
+    /// Unprojects a whole slice of [`FlatPoint`]s at once, appending each
+    /// resulting (lon, lat) coordinate to `out`.
+    ///
+    /// The batch counterpart to [`unproject`], see [`project_many`].
+    ///
+    /// [`FlatPoint`]: struct.FlatPoint.html
+    /// [`unproject`]: #method.unproject
+    /// [`project_many`]: #method.project_many
+    pub fn unproject_many(&self, points: &[FlatPoint<T>], out: &mut Vec<(T, T)>) {
+        let (kx, ky, lon, lat) = (self.kx, self.ky, self.lon, self.lat);
+
+        out.extend(points.iter().map(|p| (p.x / kx + lon, p.y / ky + lat)));
+    }
+}
+
+/// Computes the distance in kilometers from `origin` to each point in
+/// `points`, writing the result for `points[i]` into `out[i]`.
+///
+/// This is the batch counterpart to calling [`FlatPoint::distance`] in a
+/// loop, useful for e.g. ranking a whole track by distance to a fix.
+///
+/// # Panics
+///
+/// Panics if `points` and `out` have different lengths.
+///
+/// [`FlatPoint::distance`]: struct.FlatPoint.html#method.distance
+///
+/// ```
+/// # use flat_projection::{FlatProjection, distances_from};
+/// #
+/// let proj = FlatProjection::new(6.5, 51.05);
+///
+/// let origin = proj.project(6.186389, 50.823194);
+/// let points = [proj.project(6.953333, 51.301389)];
+///
+/// let mut out = [0.; 1];
+/// distances_from(&origin, &points, &mut out);
+/// ```
+pub fn distances_from<T: Float>(origin: &FlatPoint<T>, points: &[FlatPoint<T>], out: &mut [T]) {
+    assert_eq!(points.len(), out.len(), "points and out must have the same length");
+
+    let (ox, oy) = (origin.x, origin.y);
+
+    for (p, o) in points.iter().zip(out.iter_mut()) {
+        *o = distance_squared(p.x - ox, p.y - oy).sqrt();
+    }
+}
+
+/// Calculates the exact distance in kilometers and initial bearing in
+/// degrees between two (lon, lat) points, using the Vincenty inverse
+/// formula on the WGS84 ellipsoid.
+///
+/// This is much slower than projecting both points and calling
+/// [`FlatPoint::distance_bearing`], and not limited to the flat
+/// approximation's ~500 km validity window. It's intended as a reference
+/// for correctness testing, or for the rare long-distance call where the
+/// flat approximation is too coarse.
+///
+/// For nearly antipodal points the iteration may not converge; the
+/// result is then the value reached after 100 iterations and can be
+/// inaccurate.
+///
+/// [`FlatPoint::distance_bearing`]: struct.FlatPoint.html#method.distance_bearing
+///
+/// ```
+/// # use flat_projection::distance_bearing_exact;
+/// #
+/// let aachen = (6.186389, 50.823194);
+/// let meiersberg = (6.953333, 51.301389);
+///
+/// let (distance, bearing) = distance_bearing_exact(aachen, meiersberg);
+/// ```
+pub fn distance_bearing_exact<T: Float>(p1: (T, T), p2: (T, T)) -> (T, T) {
+    vincenty::distance_bearing(p1, p2)
+}
+
+/// Checks whether the given longitude and latitude (in degrees) fall
+/// within a `(west, south, east, north)` bounding box in degrees, as
+/// returned by [`buffer_point`] or [`buffer_bbox`].
+///
+/// [`buffer_point`]: struct.FlatProjection.html#method.buffer_point
+/// [`buffer_bbox`]: struct.FlatProjection.html#method.buffer_bbox
+///
+/// ```
+/// # use flat_projection::{FlatProjection, inside_bbox};
+/// #
+/// let proj = FlatProjection::new(6.5, 51.05);
+///
+/// let bbox = proj.buffer_point(6.5, 51.05, 5.);
+/// assert!(inside_bbox(6.5, 51.05, bbox));
+/// ```
+pub fn inside_bbox<T: Float>(longitude: T, latitude: T, bbox: (T, T, T, T)) -> bool {
+    longitude >= bbox.0 && longitude <= bbox.2 && latitude >= bbox.1 && latitude <= bbox.3
 }
 
 /// Representation of a geographical point on Earth as projected
@@ -375,6 +651,71 @@ impl<T: Float> FlatPoint<T> {
     }
 }
 
+/// Representation of a geographical point on Earth with an altitude, as
+/// projected by a [`FlatProjection`] instance's [`project_3d`] method.
+///
+/// Unlike [`FlatPoint`], `z` is not a flat-plane coordinate but the
+/// altitude relative to the projection's reference elevation.
+///
+/// [`FlatProjection`]: struct.FlatProjection.html
+/// [`project_3d`]: struct.FlatProjection.html#method.project_3d
+/// [`FlatPoint`]: struct.FlatPoint.html
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct FlatPoint3<T> {
+    /// X-axis component of the flat-surface point in kilometers
+    pub x: T,
+    /// Y-axis component of the flat-surface point in kilometers
+    pub y: T,
+    /// Altitude relative to the projection's reference elevation, in kilometers
+    pub z: T,
+}
+
+impl<T: Float> FlatPoint3<T> {
+    /// Calculates the approximate slant distance in kilometers from this
+    /// `FlatPoint3` to another, taking the altitude difference into
+    /// account.
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate assert_approx_eq;
+    /// # extern crate flat_projection;
+    /// #
+    /// # use flat_projection::FlatProjection;
+    /// #
+    /// # fn main() {
+    /// let proj = FlatProjection::new_with_elevation(6.5, 51.05, 0.);
+    ///
+    /// let p1 = proj.project_3d(6.186389, 50.823194, 0.2);
+    /// let p2 = proj.project_3d(6.953333, 51.301389, 0.5);
+    ///
+    /// let distance = p1.distance(&p2);
+    /// #
+    /// # assert!(distance > p1.ground_distance(&p2));
+    /// # }
+    /// ```
+    pub fn distance(&self, other: &FlatPoint3<T>) -> T {
+        self.distance_squared(other).sqrt()
+    }
+
+    /// Calculates the approximate squared slant distance from this
+    /// `FlatPoint3` to another.
+    pub fn distance_squared(&self, other: &FlatPoint3<T>) -> T {
+        let dz = self.z - other.z;
+        distance_squared(self.x - other.x, self.y - other.y) + dz.powi(2)
+    }
+
+    /// Calculates the approximate ground distance in kilometers from
+    /// this `FlatPoint3` to another, ignoring the altitude difference.
+    ///
+    /// This is equivalent to [`FlatPoint::distance`] on the `x`/`y`
+    /// components alone.
+    ///
+    /// [`FlatPoint::distance`]: struct.FlatPoint.html#method.distance
+    pub fn ground_distance(&self, other: &FlatPoint3<T>) -> T {
+        distance_squared(self.x - other.x, self.y - other.y).sqrt()
+    }
+}
+
 fn distance_squared<T: Float>(dx: T, dy: T) -> T {
     dx.powi(2) + dy.powi(2)
 }
@@ -383,12 +724,173 @@ fn bearing<T: Float>(dx: T, dy: T) -> T {
     (-dx).atan2(-dy).to_degrees()
 }
 
+/// Calculates the total length in kilometers of a line represented by
+/// a slice of [`FlatPoint`]s, by summing up the distance between each
+/// consecutive pair of points.
+///
+/// Returns `0` for slices with fewer than two points.
+///
+/// [`FlatPoint`]: struct.FlatPoint.html
+///
+/// ```
+/// # use flat_projection::{FlatProjection, line_distance};
+/// #
+/// let proj = FlatProjection::new(6.5, 51.05);
+///
+/// let points: Vec<_> = [
+///     (6.186389, 50.823194),
+///     (6.953333, 51.301389),
+/// ].iter().map(|&(lon, lat)| proj.project(lon, lat)).collect();
+///
+/// let distance = line_distance(&points);
+/// ```
+pub fn line_distance<T: Float>(points: &[FlatPoint<T>]) -> T {
+    points.windows(2).fold(T::zero(), |sum, pair| sum + pair[0].distance(&pair[1]))
+}
+
+/// Calculates the approximate area in square kilometers of a closed
+/// ring of [`FlatPoint`]s using the [shoelace formula].
+///
+/// The ring does not need to be explicitly closed; the segment from the
+/// last point back to the first is always included. Returns `0` for
+/// slices with fewer than three points.
+///
+/// [`FlatPoint`]: struct.FlatPoint.html
+/// [shoelace formula]: https://en.wikipedia.org/wiki/Shoelace_formula
+pub fn area<T: Float>(points: &[FlatPoint<T>]) -> T {
+    if points.len() < 3 {
+        return T::zero();
+    }
+
+    let two = T::from(2).unwrap();
+
+    let sum = points.iter().enumerate().fold(T::zero(), |sum, (i, p)| {
+        let next = points[(i + 1) % points.len()];
+        sum + p.x * next.y - next.x * p.y
+    });
+
+    (sum / two).abs()
+}
+
+/// Walks along a line represented by a slice of [`FlatPoint`]s and
+/// returns the point that lies `dist` kilometers along it, interpolating
+/// between the two surrounding points if necessary.
+///
+/// Returns `None` for empty slices. A `dist` of `0` or less returns the
+/// first point, and a `dist` beyond the total [`line_distance`] of the
+/// line returns the last point.
+///
+/// [`FlatPoint`]: struct.FlatPoint.html
+/// [`line_distance`]: fn.line_distance.html
+pub fn along<T: Float>(points: &[FlatPoint<T>], dist: T) -> Option<FlatPoint<T>> {
+    if points.is_empty() {
+        return None;
+    }
+
+    if dist <= T::zero() {
+        return Some(points[0]);
+    }
+
+    let mut remaining = dist;
+
+    for pair in points.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        let seg_len = a.distance(&b);
+
+        if seg_len.is_zero() {
+            continue;
+        }
+
+        if remaining <= seg_len {
+            let t = remaining / seg_len;
+            return Some(a.offset((b.x - a.x) * t, (b.y - a.y) * t));
+        }
+
+        remaining = remaining - seg_len;
+    }
+
+    points.last().copied()
+}
+
+/// Finds the point on a line represented by a slice of [`FlatPoint`]s
+/// that is closest to the given point `p`.
+///
+/// Returns the closest point itself, the index of the segment it lies
+/// on (i.e. the index of the segment's first point) and the parameter
+/// `t` in `[0, 1]` describing its position between the segment's two
+/// endpoints. Returns `None` for empty slices.
+///
+/// [`FlatPoint`]: struct.FlatPoint.html
+pub fn point_on_line<T: Float>(points: &[FlatPoint<T>], p: &FlatPoint<T>) -> Option<(FlatPoint<T>, usize, T)> {
+    if points.is_empty() {
+        return None;
+    }
+
+    if points.len() == 1 {
+        return Some((points[0], 0, T::zero()));
+    }
+
+    let mut best: Option<(FlatPoint<T>, usize, T, T)> = None;
+
+    for (i, pair) in points.windows(2).enumerate() {
+        let (a, b) = (pair[0], pair[1]);
+
+        let (abx, aby) = (b.x - a.x, b.y - a.y);
+        let seg_len_sq = distance_squared(abx, aby);
+
+        let t = if seg_len_sq.is_zero() {
+            T::zero()
+        } else {
+            let (apx, apy) = (p.x - a.x, p.y - a.y);
+            ((apx * abx + apy * aby) / seg_len_sq).max(T::zero()).min(T::one())
+        };
+
+        let candidate = a.offset(abx * t, aby * t);
+        let dist_sq = candidate.distance_squared(p);
+
+        let is_closer = match best {
+            Some((_, _, _, best_dist_sq)) => dist_sq < best_dist_sq,
+            None => true,
+        };
+
+        if is_closer {
+            best = Some((candidate, i, t, dist_sq));
+        }
+    }
+
+    best.map(|(point, i, t, _)| (point, i, t))
+}
+
 #[cfg(test)] #[macro_use] extern crate assert_approx_eq;
 
 #[cfg(test)]
 mod tests {
     use num_traits::Float;
-    use ::FlatProjection;
+    use ::{FlatProjection, FlatPoint, line_distance, area, along, point_on_line, inside_bbox, distance_bearing_exact, distances_from};
+
+    #[test]
+    fn flatpoint3_distance_accounts_for_altitude() {
+        let proj = FlatProjection::new_with_elevation(6.5, 51.05, 0.);
+
+        let p1 = proj.project_3d(6.186389, 50.823194, 0.);
+        let p2 = proj.project_3d(6.186389, 50.823194, 1.);
+
+        assert_approx_eq!(p1.distance(&p2), 1., 0.00001);
+        assert_approx_eq!(p1.ground_distance(&p2), 0., 0.00001);
+    }
+
+    #[test]
+    fn flatpoint3_roundtrips_through_unproject_3d() {
+        let (lon, lat, alt) = (6.186389, 50.823194, 0.189);
+        let proj = FlatProjection::new_with_elevation(6., 51., 0.1);
+
+        let flat_point = proj.project_3d(lon, lat, alt);
+        let result = proj.unproject_3d(&flat_point);
+
+        assert_eq!(result.0, lon);
+        assert_eq!(result.1, lat);
+        assert_eq!(result.2, alt);
+    }
 
     #[test]
     fn flatpoint_destination_ne() {
@@ -450,4 +952,194 @@ mod tests {
         assert_approx_eq!(dest_lat, 50.5063572, 0.00001);
         assert_approx_eq!(distance, res_distance, 0.00001);
     }
+
+    #[test]
+    fn line_distance_empty_and_single() {
+        let empty: Vec<FlatPoint<f64>> = vec![];
+        assert_eq!(line_distance(&empty), 0.);
+
+        let single = vec![FlatPoint { x: 1., y: 1. }];
+        assert_eq!(line_distance(&single), 0.);
+    }
+
+    #[test]
+    fn line_distance_sums_segments() {
+        let points = vec![
+            FlatPoint { x: 0., y: 0. },
+            FlatPoint { x: 3., y: 0. },
+            FlatPoint { x: 3., y: 4. },
+        ];
+        assert_approx_eq!(line_distance(&points), 7., 0.00001);
+    }
+
+    #[test]
+    fn area_of_square() {
+        let points = vec![
+            FlatPoint { x: 0., y: 0. },
+            FlatPoint { x: 2., y: 0. },
+            FlatPoint { x: 2., y: 2. },
+            FlatPoint { x: 0., y: 2. },
+        ];
+        assert_approx_eq!(area(&points), 4., 0.00001);
+    }
+
+    #[test]
+    fn area_of_degenerate_ring() {
+        let points = vec![FlatPoint { x: 0., y: 0. }, FlatPoint { x: 1., y: 1. }];
+        assert_eq!(area(&points), 0.);
+    }
+
+    #[test]
+    fn along_interpolates_between_points() {
+        let points = vec![
+            FlatPoint { x: 0., y: 0. },
+            FlatPoint { x: 10., y: 0. },
+        ];
+
+        let p = along(&points, 4.).unwrap();
+        assert_approx_eq!(p.x, 4., 0.00001);
+        assert_approx_eq!(p.y, 0., 0.00001);
+    }
+
+    #[test]
+    fn along_clamps_to_ends() {
+        let points = vec![
+            FlatPoint { x: 0., y: 0. },
+            FlatPoint { x: 10., y: 0. },
+        ];
+
+        assert_eq!(along(&points, -1.).unwrap(), points[0]);
+        assert_eq!(along(&points, 100.).unwrap(), points[1]);
+        assert!(along(&([] as [FlatPoint<f64>; 0]), 1.).is_none());
+    }
+
+    #[test]
+    fn point_on_line_projects_onto_closest_segment() {
+        let points = vec![
+            FlatPoint { x: 0., y: 0. },
+            FlatPoint { x: 10., y: 0. },
+            FlatPoint { x: 10., y: 10. },
+        ];
+
+        let (point, segment, t) = point_on_line(&points, &FlatPoint { x: 12., y: 4. }).unwrap();
+        assert_approx_eq!(point.x, 10., 0.00001);
+        assert_approx_eq!(point.y, 4., 0.00001);
+        assert_eq!(segment, 1);
+        assert_approx_eq!(t, 0.4, 0.00001);
+    }
+
+    #[test]
+    fn buffer_point_contains_origin() {
+        let proj = FlatProjection::new(6.5, 51.05);
+        let bbox = proj.buffer_point(6.5, 51.05, 5.);
+
+        assert!(inside_bbox(6.5, 51.05, bbox));
+        assert!(!inside_bbox(10., 51.05, bbox));
+    }
+
+    #[test]
+    fn buffer_bbox_pads_all_sides() {
+        let proj = FlatProjection::new(6.5, 51.05);
+        let bbox = proj.buffer_bbox((6.4, 51.0, 6.6, 51.1), 5.);
+
+        assert!(bbox.0 < 6.4);
+        assert!(bbox.1 < 51.0);
+        assert!(bbox.2 > 6.6);
+        assert!(bbox.3 > 51.1);
+    }
+
+    #[test]
+    fn destination_exact_matches_destination() {
+        let (lon, lat) = (30.5, 50.5);
+        let proj = FlatProjection::new(31., 50.);
+
+        let (dist, bearing) = (1., 45.0);
+
+        let p1 = proj.project(lon, lat);
+        let p2 = p1.destination(dist, bearing);
+        let (fast_lon, fast_lat) = proj.unproject(&p2);
+
+        let (exact_lon, exact_lat) = proj.destination_exact(lon, lat, dist, bearing);
+
+        assert_approx_eq!(exact_lon, fast_lon, 0.001);
+        assert_approx_eq!(exact_lat, fast_lat, 0.001);
+
+        const VINCENTY_DEST_LON: f64 = 30.509_967_685;
+        const VINCENTY_DEST_LAT: f64 = 50.506_356_232;
+
+        assert_approx_eq!(exact_lon, VINCENTY_DEST_LON, 0.00001);
+        assert_approx_eq!(exact_lat, VINCENTY_DEST_LAT, 0.00001);
+    }
+
+    #[test]
+    fn distance_bearing_exact_matches_known_vincenty_values() {
+        let aachen = (6.186389, 50.823194);
+        let meiersberg = (6.953333, 51.301389);
+
+        let (distance, bearing) = distance_bearing_exact(aachen, meiersberg);
+
+        const VINCENTY_DISTANCE: f64 = 75.635_595;
+        const VINCENTY_INITIAL_BEARING: f64 = 45.005_741;
+
+        assert_approx_eq!(distance, VINCENTY_DISTANCE, 0.001);
+        assert_approx_eq!(bearing, VINCENTY_INITIAL_BEARING, 0.001);
+    }
+
+    #[test]
+    fn distance_bearing_exact_of_coincident_points_is_zero() {
+        let p = (6.186389, 50.823194);
+        assert_eq!(distance_bearing_exact(p, p), (0., 0.));
+    }
+
+    #[test]
+    fn project_many_matches_project() {
+        let proj = FlatProjection::new(6.5, 51.05);
+        let coords = [(6.186389, 50.823194), (6.953333, 51.301389)];
+
+        let mut points = Vec::new();
+        proj.project_many(&coords, &mut points);
+
+        assert_eq!(points.len(), coords.len());
+        for (&(lon, lat), &point) in coords.iter().zip(points.iter()) {
+            assert_eq!(point, proj.project(lon, lat));
+        }
+    }
+
+    #[test]
+    fn unproject_many_matches_unproject() {
+        let proj = FlatProjection::new(6.5, 51.05);
+        let points = [proj.project(6.186389, 50.823194), proj.project(6.953333, 51.301389)];
+
+        let mut coords = Vec::new();
+        proj.unproject_many(&points, &mut coords);
+
+        assert_eq!(coords.len(), points.len());
+        for (&point, &coord) in points.iter().zip(coords.iter()) {
+            assert_eq!(coord, proj.unproject(&point));
+        }
+    }
+
+    #[test]
+    fn distances_from_matches_distance() {
+        let proj = FlatProjection::new(6.5, 51.05);
+        let origin = proj.project(6.186389, 50.823194);
+        let points = [proj.project(6.953333, 51.301389), origin];
+
+        let mut out = [0.; 2];
+        distances_from(&origin, &points, &mut out);
+
+        assert_eq!(out[0], origin.distance(&points[0]));
+        assert_eq!(out[1], 0.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn distances_from_panics_on_length_mismatch() {
+        let proj = FlatProjection::new(6.5, 51.05);
+        let origin = proj.project(6.186389, 50.823194);
+        let points = [proj.project(6.953333, 51.301389)];
+
+        let mut out = [0.; 2];
+        distances_from(&origin, &points, &mut out);
+    }
 }
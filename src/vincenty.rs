@@ -0,0 +1,159 @@
+//! High-accuracy geodesic formulas on the WGS84 ellipsoid, for callers
+//! who need an exact reference value instead of the fast flat-plane
+//! approximation used by the rest of this crate.
+//!
+//! These are considerably more expensive than [`FlatPoint::distance`]/
+//! [`FlatPoint::destination`] and should be reserved for validation or
+//! for the rare long-distance call where the flat approximation's
+//! ~500 km validity window is exceeded.
+//!
+//! [`FlatPoint::distance`]: ../struct.FlatPoint.html#method.distance
+//! [`FlatPoint::destination`]: ../struct.FlatPoint.html#method.destination
+
+use num_traits::Float;
+
+// Values that define the WGS84 ellipsoid model of the Earth, in kilometers.
+fn ellipsoid<T: Float>() -> (T, T, T) {
+    let a: T = T::from(6378.137).unwrap(); // semi-major axis
+    let b: T = T::from(6356.752314245).unwrap(); // semi-minor axis
+    let f: T = T::one() / T::from(298.257223563).unwrap(); // flattening
+
+    (a, b, f)
+}
+
+/// Vincenty direct formula: given a starting point, a distance in
+/// kilometers and an initial bearing in degrees, finds the destination
+/// point on the WGS84 ellipsoid.
+pub fn destination<T: Float>(longitude: T, latitude: T, dist: T, bearing: T) -> (T, T) {
+    let (a, b, f) = ellipsoid::<T>();
+    let one = T::one();
+    let two = T::from(2).unwrap();
+
+    let alpha1 = bearing.to_radians();
+    let (sin_alpha1, cos_alpha1) = (alpha1.sin(), alpha1.cos());
+
+    let tan_u1 = (one - f) * latitude.to_radians().tan();
+    let cos_u1 = one / (one + tan_u1 * tan_u1).sqrt();
+    let sin_u1 = tan_u1 * cos_u1;
+
+    let sigma1 = tan_u1.atan2(cos_alpha1);
+    let sin_alpha = cos_u1 * sin_alpha1;
+    let cos_sq_alpha = one - sin_alpha * sin_alpha;
+
+    let u_sq = cos_sq_alpha * (a * a - b * b) / (b * b);
+    let cap_a = one + u_sq / T::from(16384).unwrap() *
+        (T::from(4096).unwrap() + u_sq * (T::from(-768).unwrap() + u_sq * (T::from(320).unwrap() - T::from(175).unwrap() * u_sq)));
+    let cap_b = u_sq / T::from(1024).unwrap() *
+        (T::from(256).unwrap() + u_sq * (T::from(-128).unwrap() + u_sq * (T::from(74).unwrap() - T::from(47).unwrap() * u_sq)));
+
+    let mut sigma = dist / (b * cap_a);
+    let mut cos2_sigma_m;
+    let mut sin_sigma;
+    let mut cos_sigma;
+    let mut iter_limit = 100;
+
+    loop {
+        cos2_sigma_m = (two * sigma1 + sigma).cos();
+        sin_sigma = sigma.sin();
+        cos_sigma = sigma.cos();
+
+        let delta_sigma = cap_b * sin_sigma * (cos2_sigma_m + cap_b / T::from(4).unwrap() *
+            (cos_sigma * (-one + two * cos2_sigma_m * cos2_sigma_m) -
+                cap_b / T::from(6).unwrap() * cos2_sigma_m * (-T::from(3).unwrap() + T::from(4).unwrap() * sin_sigma * sin_sigma) *
+                    (-T::from(3).unwrap() + T::from(4).unwrap() * cos2_sigma_m * cos2_sigma_m)));
+
+        let sigma_p = sigma;
+        sigma = dist / (b * cap_a) + delta_sigma;
+
+        iter_limit -= 1;
+
+        if (sigma - sigma_p).abs() <= T::from(1e-12).unwrap() || iter_limit == 0 {
+            break;
+        }
+    }
+
+    let x = sin_u1 * sin_sigma - cos_u1 * cos_sigma * cos_alpha1;
+    let lat2 = (sin_u1 * cos_sigma + cos_u1 * sin_sigma * cos_alpha1)
+        .atan2((one - f) * (sin_alpha * sin_alpha + x * x).sqrt());
+
+    let lambda = (sin_sigma * sin_alpha1).atan2(cos_u1 * cos_sigma - sin_u1 * sin_sigma * cos_alpha1);
+    let c = f / T::from(16).unwrap() * cos_sq_alpha * (T::from(4).unwrap() + f * (T::from(4).unwrap() - T::from(3).unwrap() * cos_sq_alpha));
+    let l = lambda - (one - c) * f * sin_alpha *
+        (sigma + c * sin_sigma * (cos2_sigma_m + c * cos_sigma * (-one + two * cos2_sigma_m * cos2_sigma_m)));
+
+    let lon2 = longitude.to_radians() + l;
+
+    (lon2.to_degrees(), lat2.to_degrees())
+}
+
+/// Vincenty inverse formula: finds the distance in kilometers and the
+/// initial bearing in degrees between two points on the WGS84 ellipsoid.
+pub fn distance_bearing<T: Float>(p1: (T, T), p2: (T, T)) -> (T, T) {
+    let (a, b, f) = ellipsoid::<T>();
+    let one = T::one();
+    let two = T::from(2).unwrap();
+
+    let l = (p2.0 - p1.0).to_radians();
+    let u1 = ((one - f) * p1.1.to_radians().tan()).atan();
+    let u2 = ((one - f) * p2.1.to_radians().tan()).atan();
+    let (sin_u1, cos_u1) = (u1.sin(), u1.cos());
+    let (sin_u2, cos_u2) = (u2.sin(), u2.cos());
+
+    let mut lambda = l;
+    let mut iter_limit = 100;
+
+    let (mut sin_sigma, mut cos_sigma, mut sigma, mut cos_sq_alpha, mut cos2_sigma_m, mut sin_alpha1, mut cos_alpha1);
+
+    loop {
+        let sin_lambda = lambda.sin();
+        let cos_lambda = lambda.cos();
+
+        sin_sigma = ((cos_u2 * sin_lambda) * (cos_u2 * sin_lambda) +
+            (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda) * (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda)).sqrt();
+
+        if sin_sigma.is_zero() {
+            // co-incident points
+            return (T::zero(), T::zero());
+        }
+
+        cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        sigma = sin_sigma.atan2(cos_sigma);
+
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        cos_sq_alpha = one - sin_alpha * sin_alpha;
+        cos2_sigma_m = cos_sigma - two * sin_u1 * sin_u2 / cos_sq_alpha;
+        if cos2_sigma_m.is_nan() {
+            cos2_sigma_m = T::zero(); // equatorial line: cos_sq_alpha = 0
+        }
+
+        sin_alpha1 = cos_u2 * sin_lambda;
+        cos_alpha1 = cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda;
+
+        let c = f / T::from(16).unwrap() * cos_sq_alpha * (T::from(4).unwrap() + f * (T::from(4).unwrap() - T::from(3).unwrap() * cos_sq_alpha));
+        let lambda_p = lambda;
+        lambda = l + (one - c) * f * sin_alpha *
+            (sigma + c * sin_sigma * (cos2_sigma_m + c * cos_sigma * (-one + two * cos2_sigma_m * cos2_sigma_m)));
+
+        iter_limit -= 1;
+
+        if (lambda - lambda_p).abs() <= T::from(1e-12).unwrap() || iter_limit == 0 {
+            break;
+        }
+    }
+
+    let u_sq = cos_sq_alpha * (a * a - b * b) / (b * b);
+    let cap_a = one + u_sq / T::from(16384).unwrap() *
+        (T::from(4096).unwrap() + u_sq * (T::from(-768).unwrap() + u_sq * (T::from(320).unwrap() - T::from(175).unwrap() * u_sq)));
+    let cap_b = u_sq / T::from(1024).unwrap() *
+        (T::from(256).unwrap() + u_sq * (T::from(-128).unwrap() + u_sq * (T::from(74).unwrap() - T::from(47).unwrap() * u_sq)));
+
+    let delta_sigma = cap_b * sin_sigma * (cos2_sigma_m + cap_b / T::from(4).unwrap() *
+        (cos_sigma * (-one + two * cos2_sigma_m * cos2_sigma_m) -
+            cap_b / T::from(6).unwrap() * cos2_sigma_m * (-T::from(3).unwrap() + T::from(4).unwrap() * sin_sigma * sin_sigma) *
+                (-T::from(3).unwrap() + T::from(4).unwrap() * cos2_sigma_m * cos2_sigma_m)));
+
+    let dist = b * cap_a * (sigma - delta_sigma);
+    let bearing = sin_alpha1.atan2(cos_alpha1).to_degrees();
+
+    (dist, bearing)
+}